@@ -0,0 +1,92 @@
+use anyhow::{anyhow, Result as AnyhowResult};
+
+/// Parses a human-readable token amount (e.g. `"1.25"`) into its
+/// smallest-unit `u64` representation given the mint's real `decimals`,
+/// using checked arithmetic throughout so an amount that doesn't fit
+/// errors out instead of silently clamping. Shared by every command that
+/// accepts a token amount: treasury withdrawals, reward-center funding,
+/// and listing/offer prices.
+pub fn parse_amount(amount: &str, decimals: u8) -> AnyhowResult<u64> {
+    let mut parts = amount.splitn(2, '.');
+    let whole_part = parts.next().unwrap_or("");
+    let fraction_part = parts.next().unwrap_or("");
+
+    if fraction_part.len() > decimals as usize {
+        return Err(anyhow!(
+            "Amount {} has more decimal places than the mint supports ({})",
+            amount,
+            decimals
+        ));
+    }
+
+    let whole: u64 = if whole_part.is_empty() {
+        0
+    } else {
+        whole_part
+            .parse()
+            .map_err(|_| anyhow!("Invalid amount: {}", amount))?
+    };
+
+    let padded_fraction = format!("{:0<width$}", fraction_part, width = decimals as usize);
+    let fraction: u64 = if padded_fraction.is_empty() {
+        0
+    } else {
+        padded_fraction
+            .parse()
+            .map_err(|_| anyhow!("Invalid amount: {}", amount))?
+    };
+
+    let scale = 10u64
+        .checked_pow(decimals.into())
+        .ok_or_else(|| anyhow!("Mint decimals {} is too large", decimals))?;
+
+    whole
+        .checked_mul(scale)
+        .and_then(|whole_scaled| whole_scaled.checked_add(fraction))
+        .ok_or_else(|| {
+            anyhow!(
+                "Amount {} overflows a u64 once scaled by the mint's decimals",
+                amount
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_whole_amount() {
+        assert_eq!(parse_amount("5", 6).unwrap(), 5_000_000);
+    }
+
+    #[test]
+    fn parses_fractional_amount() {
+        assert_eq!(parse_amount("1.25", 6).unwrap(), 1_250_000);
+    }
+
+    #[test]
+    fn parses_fraction_only_amount() {
+        assert_eq!(parse_amount(".5", 6).unwrap(), 500_000);
+    }
+
+    #[test]
+    fn rejects_more_decimals_than_mint_supports() {
+        assert!(parse_amount("1.1234567", 6).is_err());
+    }
+
+    #[test]
+    fn rejects_overflow_once_scaled() {
+        assert!(parse_amount(&u64::MAX.to_string(), 9).is_err());
+    }
+
+    #[test]
+    fn rejects_garbage_input() {
+        assert!(parse_amount("not-a-number", 6).is_err());
+    }
+
+    #[test]
+    fn empty_input_parses_to_zero() {
+        assert_eq!(parse_amount("", 6).unwrap(), 0);
+    }
+}
@@ -0,0 +1,207 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anchor_lang::AnchorDeserialize;
+use anyhow::{Context, Result as AnyhowResult};
+use hpl_reward_center::{
+    metaplex_cpi::is_programmable,
+    offers::accept::AcceptOfferParams,
+    pda::{find_bid_receipt_address, find_purchase_receipt_address, find_reward_center_address},
+    state::Offer,
+};
+use hpl_reward_center_sdk::{accept_offer, accounts::AcceptOfferAccounts};
+use log::info;
+use mpl_auction_house::{
+    pda::{
+        find_auction_house_fee_address, find_auction_house_treasury_address, find_auctioneer_pda,
+        find_escrow_payment_address, find_program_as_signer_address, find_trade_state_address,
+    },
+    AuctionHouse,
+};
+use mpl_token_metadata::{pda::find_token_record_account, state::Metadata};
+use retry::{delay::Exponential, retry};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    sysvar,
+};
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, signer::Signer, transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::config::{parse_keypair, parse_solana_config};
+
+/// Mirrors `buy_listing`'s compute budget bump: accepting an offer on a
+/// programmable NFT runs the same rule-set validation plus transfer CPI
+/// chain and overruns the default 200k compute unit budget.
+const ACCEPT_OFFER_COMPUTE_UNIT_LIMIT: u32 = 400_000;
+
+/// # Errors
+///
+/// Will return `Err` if the pubkeys fail to parse, the offer account
+/// can't be fetched, or the transaction can't be confirmed.
+pub fn process_accept_offer(
+    client: RpcClient,
+    keypair_path: Option<PathBuf>,
+    auction_house: String,
+    offer: String,
+    dry_run: bool,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_config()?;
+    let keypair = parse_keypair(&keypair_path, &solana_options)?;
+
+    let auction_house_pubkey = Pubkey::from_str(&auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+    let offer_pubkey =
+        Pubkey::from_str(&offer).context("Failed to parse Pubkey from offer string")?;
+
+    let offer_data = client.get_account_data(&offer_pubkey)?;
+    let Offer {
+        buyer,
+        metadata,
+        price,
+        token_size,
+        ..
+    } = Offer::deserialize(&mut &offer_data[8..])?;
+
+    let auction_house_data = client.get_account_data(&auction_house_pubkey)?;
+    let AuctionHouse {
+        authority,
+        treasury_mint,
+        ..
+    } = AuctionHouse::deserialize(&mut &auction_house_data[8..])?;
+
+    let (reward_center_pubkey, _) = find_reward_center_address(&auction_house_pubkey);
+    let (ah_auctioneer_pda, _) = find_auctioneer_pda(&auction_house_pubkey, &reward_center_pubkey);
+    let (auction_house_fee_account, _) = find_auction_house_fee_address(&auction_house_pubkey);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house_pubkey);
+
+    let metadata_data = client
+        .get_account_data(&metadata)
+        .context("Failed to fetch metadata account for offer")?;
+    let offer_metadata = Metadata::deserialize(&mut &metadata_data[..])?;
+    let mint = offer_metadata.mint;
+
+    let token_account_pubkey = get_associated_token_address(&keypair.pubkey(), &mint);
+    let buyer_receipt_token_account = get_associated_token_address(&buyer, &mint);
+
+    let (escrow_payment_account, escrow_payment_bump) =
+        find_escrow_payment_address(&auction_house_pubkey, &buyer);
+    let (free_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &keypair.pubkey(),
+        &auction_house_pubkey,
+        &token_account_pubkey,
+        &treasury_mint,
+        &mint,
+        0,
+        token_size,
+    );
+    let (seller_trade_state, _) = find_trade_state_address(
+        &keypair.pubkey(),
+        &auction_house_pubkey,
+        &token_account_pubkey,
+        &treasury_mint,
+        &mint,
+        price,
+        token_size,
+    );
+    let (buyer_trade_state, _) = find_trade_state_address(
+        &buyer,
+        &auction_house_pubkey,
+        &token_account_pubkey,
+        &treasury_mint,
+        &mint,
+        price,
+        token_size,
+    );
+    let (program_as_signer, program_as_signer_bump) = find_program_as_signer_address();
+
+    let (bid_receipt, _) = find_bid_receipt_address(&offer_pubkey);
+    let (purchase_receipt, _) =
+        find_purchase_receipt_address(&seller_trade_state, &buyer_trade_state);
+
+    let mut accept_offer_ix = accept_offer(
+        AcceptOfferAccounts {
+            buyer,
+            seller: keypair.pubkey(),
+            token_account: token_account_pubkey,
+            token_mint: mint,
+            metadata,
+            treasury_mint,
+            escrow_payment_account,
+            seller_payment_receipt_account: keypair.pubkey(),
+            buyer_receipt_token_account,
+            authority,
+            reward_center: reward_center_pubkey,
+            auction_house: auction_house_pubkey,
+            ah_auctioneer_pda,
+            auction_house_fee_account,
+            auction_house_treasury,
+            buyer_trade_state,
+            seller_trade_state,
+            free_trade_state,
+            offer: offer_pubkey,
+            bid_receipt,
+            purchase_receipt,
+            program_as_signer,
+        },
+        AcceptOfferParams {
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+        },
+    );
+
+    if is_programmable(&offer_metadata) {
+        let (owner_token_record, _) = find_token_record_account(&mint, &token_account_pubkey);
+        let (destination_token_record, _) =
+            find_token_record_account(&mint, &buyer_receipt_token_account);
+        let authorization_rules = match offer_metadata.programmable_config {
+            Some(mpl_token_metadata::state::ProgrammableConfig::V1 { rule_set }) => rule_set,
+            None => None,
+        };
+
+        accept_offer_ix.accounts.extend([
+            AccountMeta::new(owner_token_record, false),
+            AccountMeta::new(destination_token_record, false),
+            AccountMeta::new_readonly(
+                authorization_rules.unwrap_or(mpl_token_auth_rules::id()),
+                false,
+            ),
+            AccountMeta::new_readonly(mpl_token_auth_rules::id(), false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        ]);
+    }
+
+    let instructions: Vec<Instruction> = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(ACCEPT_OFFER_COMPUTE_UNIT_LIMIT),
+        accept_offer_ix,
+    ];
+
+    if dry_run {
+        crate::commands::print_dry_run(&instructions);
+
+        info!("Purchase receipt address (dry run): {}", purchase_receipt);
+
+        return Ok(());
+    }
+
+    let latest_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        latest_blockhash,
+    );
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!("Accepted offer in tx: {:?}", &tx_hash);
+    info!("Purchase receipt address: {}", purchase_receipt);
+
+    Ok(())
+}
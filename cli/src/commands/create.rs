@@ -34,6 +34,7 @@ pub fn process_create_reward_center(
     config_file: PathBuf,
     auction_house: Option<String>,
     mint_rewards: Option<String>,
+    dry_run: bool,
 ) -> AnyhowResult<()> {
     let solana_options = parse_solana_config()?;
 
@@ -144,10 +145,10 @@ pub fn process_create_reward_center(
 
     let create_reward_center_ix = create_reward_center(
         CreateRewardCenterAccounts {
-            wallet: todo!(),
-            mint: todo!(),
-            auction_house: todo!(),
-            auction_house_treasury_mint: todo!(),
+            wallet: keypair.pubkey(),
+            mint: rewards_mint_pubkey,
+            auction_house: auction_house_pubkey,
+            auction_house_treasury_mint: wsol_mint,
         },
         hpl_reward_center::reward_centers::create::CreateRewardCenterParams {
             reward_rules: {
@@ -156,10 +157,10 @@ pub fn process_create_reward_center(
                     mathematical_operand: match mathematical_operand {
                         PayoutOperation::Divide => {
                             hpl_reward_center::state::PayoutOperation::Divide
-                        },
+                        }
                         PayoutOperation::Multiple => {
                             hpl_reward_center::state::PayoutOperation::Multiple
-                        },
+                        }
                     },
                     payout_numeral,
                 }
@@ -169,6 +170,17 @@ pub fn process_create_reward_center(
 
     instructions.push(create_reward_center_ix);
 
+    if dry_run {
+        crate::commands::print_dry_run(&instructions);
+
+        info!(
+            "Reward center address (dry run): {}\n",
+            reward_center_pubkey
+        );
+
+        return Ok(());
+    }
+
     let latest_blockhash = client.get_latest_blockhash()?;
 
     let transaction = if mint_rewards.is_some() {
@@ -0,0 +1,103 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anyhow::{Context, Result as AnyhowResult};
+use hpl_reward_center::{
+    listings::create::CreateListingParams,
+    pda::{find_listing_address, find_listing_receipt_address, find_reward_center_address},
+};
+use hpl_reward_center_sdk::{accounts::CreateListingAccounts, create_listing};
+use log::info;
+use mpl_token_metadata::pda::find_metadata_account;
+use retry::{delay::Exponential, retry};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Mint;
+
+use crate::{
+    amount::parse_amount,
+    config::{parse_keypair, parse_solana_config},
+};
+
+/// # Errors
+///
+/// Will return `Err` if the auction house or mint pubkeys fail to parse
+/// or the transaction can't be confirmed.
+pub fn process_create_listing(
+    client: RpcClient,
+    keypair_path: Option<PathBuf>,
+    auction_house: String,
+    token_mint: String,
+    price: &str,
+    token_size: u64,
+    dry_run: bool,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_config()?;
+    let keypair = parse_keypair(&keypair_path, &solana_options)?;
+
+    let auction_house_pubkey = Pubkey::from_str(&auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+    let token_mint_pubkey =
+        Pubkey::from_str(&token_mint).context("Failed to parse Pubkey from token mint string")?;
+
+    let auction_house_data = client
+        .get_account_data(&auction_house_pubkey)
+        .context("Failed to get auction house data")?;
+    let mpl_auction_house::AuctionHouse { treasury_mint, .. } =
+        anchor_lang::AnchorDeserialize::deserialize(&mut &auction_house_data[8..])?;
+    let treasury_mint_data = client.get_account_data(&treasury_mint)?;
+    let Mint { decimals, .. } = Mint::unpack(&treasury_mint_data)?;
+    let price = parse_amount(price, decimals)?;
+
+    let (reward_center_pubkey, _) = find_reward_center_address(&auction_house_pubkey);
+    let (metadata_pubkey, _) = find_metadata_account(&token_mint_pubkey);
+    let token_account_pubkey = get_associated_token_address(&keypair.pubkey(), &token_mint_pubkey);
+    let (listing_pubkey, _) =
+        find_listing_address(&keypair.pubkey(), &metadata_pubkey, &reward_center_pubkey);
+    let (listing_receipt, _) = find_listing_receipt_address(&listing_pubkey);
+
+    let create_listing_ix = create_listing(
+        CreateListingAccounts {
+            wallet: keypair.pubkey(),
+            metadata: metadata_pubkey,
+            token_account: token_account_pubkey,
+            token_mint: token_mint_pubkey,
+            auction_house: auction_house_pubkey,
+            reward_center: reward_center_pubkey,
+            listing: listing_pubkey,
+            listing_receipt,
+        },
+        CreateListingParams { price, token_size },
+    );
+
+    let instructions: Vec<Instruction> = vec![create_listing_ix];
+
+    if dry_run {
+        crate::commands::print_dry_run(&instructions);
+
+        info!("Listing address (dry run): {}", listing_pubkey);
+        info!("Listing receipt address (dry run): {}", listing_receipt);
+
+        return Ok(());
+    }
+
+    let latest_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        latest_blockhash,
+    );
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!("Listing address: {}", listing_pubkey);
+    info!("Listing receipt address: {}", listing_receipt);
+    info!("Created in tx: {:?}", &tx_hash);
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anyhow::{Context, Result as AnyhowResult};
+use hpl_reward_center::{
+    offers::create::CreateOfferParams,
+    pda::{find_bid_receipt_address, find_offer_address, find_reward_center_address},
+};
+use hpl_reward_center_sdk::{accounts::CreateOfferAccounts, create_offer};
+use log::info;
+use mpl_token_metadata::pda::find_metadata_account;
+use retry::{delay::Exponential, retry};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_token::state::Mint;
+
+use crate::{
+    amount::parse_amount,
+    config::{parse_keypair, parse_solana_config},
+};
+
+/// # Errors
+///
+/// Will return `Err` if the auction house or mint pubkeys fail to parse
+/// or the transaction can't be confirmed.
+pub fn process_create_offer(
+    client: RpcClient,
+    keypair_path: Option<PathBuf>,
+    auction_house: String,
+    token_mint: String,
+    price: &str,
+    token_size: u64,
+    dry_run: bool,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_config()?;
+    let keypair = parse_keypair(&keypair_path, &solana_options)?;
+
+    let auction_house_pubkey = Pubkey::from_str(&auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+    let token_mint_pubkey =
+        Pubkey::from_str(&token_mint).context("Failed to parse Pubkey from token mint string")?;
+
+    let auction_house_data = client
+        .get_account_data(&auction_house_pubkey)
+        .context("Failed to get auction house data")?;
+    let mpl_auction_house::AuctionHouse { treasury_mint, .. } =
+        anchor_lang::AnchorDeserialize::deserialize(&mut &auction_house_data[8..])?;
+    let treasury_mint_data = client.get_account_data(&treasury_mint)?;
+    let Mint { decimals, .. } = Mint::unpack(&treasury_mint_data)?;
+    let price = parse_amount(price, decimals)?;
+
+    let (reward_center_pubkey, _) = find_reward_center_address(&auction_house_pubkey);
+    let (metadata_pubkey, _) = find_metadata_account(&token_mint_pubkey);
+    let (offer_pubkey, _) =
+        find_offer_address(&keypair.pubkey(), &metadata_pubkey, &reward_center_pubkey);
+    let (bid_receipt, _) = find_bid_receipt_address(&offer_pubkey);
+
+    let create_offer_ix = create_offer(
+        CreateOfferAccounts {
+            wallet: keypair.pubkey(),
+            metadata: metadata_pubkey,
+            reward_center: reward_center_pubkey,
+            offer: offer_pubkey,
+            bid_receipt,
+        },
+        CreateOfferParams { price, token_size },
+    );
+
+    let instructions: Vec<Instruction> = vec![create_offer_ix];
+
+    if dry_run {
+        crate::commands::print_dry_run(&instructions);
+
+        info!("Offer address (dry run): {}", offer_pubkey);
+        info!("Bid receipt address (dry run): {}", bid_receipt);
+
+        return Ok(());
+    }
+
+    let latest_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        latest_blockhash,
+    );
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!("Offer address: {}", offer_pubkey);
+    info!("Bid receipt address: {}", bid_receipt);
+    info!("Created in tx: {:?}", &tx_hash);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anyhow::{Context, Result as AnyhowResult};
+use hpl_reward_center::{pda::find_reward_center_address, reward_centers::delegate::DelegateScope};
+use hpl_reward_center_sdk::{accounts::DelegateAuctioneerAccounts, delegate_auctioneer};
+use log::info;
+use mpl_auction_house::pda::find_auctioneer_pda;
+use retry::{delay::Exponential, retry};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::config::{parse_keypair, parse_solana_config};
+
+/// # Errors
+///
+/// Will return `Err` if the auction house pubkey fails to parse or the
+/// transaction can't be confirmed.
+pub fn process_delegate_auctioneer(
+    client: &RpcClient,
+    keypair_path: &Option<PathBuf>,
+    auction_house: &str,
+    scopes: Vec<DelegateScope>,
+    revoke: bool,
+    dry_run: bool,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_config()?;
+    let keypair = parse_keypair(keypair_path, &solana_options)?;
+
+    let auction_house_pubkey = Pubkey::from_str(auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+
+    let (reward_center_pubkey, _) = find_reward_center_address(&auction_house_pubkey);
+    let (auctioneer_pda_pubkey, _) =
+        find_auctioneer_pda(&auction_house_pubkey, &reward_center_pubkey);
+
+    let delegate_auctioneer_ix = delegate_auctioneer(
+        DelegateAuctioneerAccounts {
+            wallet: keypair.pubkey(),
+            auction_house: auction_house_pubkey,
+            reward_center: reward_center_pubkey,
+            authority: keypair.pubkey(),
+            auctioneer_pda: auctioneer_pda_pubkey,
+        },
+        scopes.clone(),
+        revoke,
+    );
+
+    let instructions: Vec<Instruction> = vec![delegate_auctioneer_ix];
+
+    if dry_run {
+        crate::commands::print_dry_run(&instructions);
+
+        return Ok(());
+    }
+
+    let latest_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        latest_blockhash,
+    );
+
+    if revoke {
+        info!("Revoking scopes {:?} from {}", scopes, reward_center_pubkey);
+    } else {
+        info!("Delegating scopes {:?} to {}", scopes, reward_center_pubkey);
+    }
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!("Delegated in tx: {:?}", &tx_hash);
+
+    Ok(())
+}
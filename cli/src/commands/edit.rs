@@ -0,0 +1,84 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anyhow::{Context, Result as AnyhowResult};
+use hpl_reward_center::pda::find_reward_center_address;
+use hpl_reward_center_sdk::{accounts::EditRewardCenterAccounts, edit_reward_center};
+use log::info;
+use retry::{delay::Exponential, retry};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::{
+    config::{parse_keypair, parse_solana_config},
+    schema::{EditRewardCenterParams, PayoutOperation},
+};
+
+/// # Errors
+///
+/// Will return `Err` if the auction house pubkey fails to parse or the
+/// transaction can't be confirmed.
+pub fn process_edit_reward_center(
+    client: RpcClient,
+    keypair_path: Option<PathBuf>,
+    auction_house: String,
+    EditRewardCenterParams {
+        mathematical_operand,
+        payout_numeral,
+        seller_reward_payout_basis_points,
+    }: EditRewardCenterParams,
+    dry_run: bool,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_config()?;
+    let keypair = parse_keypair(&keypair_path, &solana_options)?;
+
+    let auction_house_pubkey = Pubkey::from_str(&auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+
+    let (reward_center_pubkey, _) = find_reward_center_address(&auction_house_pubkey);
+
+    let edit_reward_center_ix = edit_reward_center(
+        EditRewardCenterAccounts {
+            wallet: keypair.pubkey(),
+            auction_house: auction_house_pubkey,
+            reward_center: reward_center_pubkey,
+        },
+        hpl_reward_center::reward_centers::edit::EditRewardCenterParams {
+            reward_rules: hpl_reward_center::state::RewardRules {
+                seller_reward_payout_basis_points,
+                mathematical_operand: match mathematical_operand {
+                    PayoutOperation::Divide => hpl_reward_center::state::PayoutOperation::Divide,
+                    PayoutOperation::Multiple => {
+                        hpl_reward_center::state::PayoutOperation::Multiple
+                    }
+                },
+                payout_numeral,
+            },
+        },
+    );
+
+    let instructions: Vec<Instruction> = vec![edit_reward_center_ix];
+
+    if dry_run {
+        crate::commands::print_dry_run(&instructions);
+
+        return Ok(());
+    }
+
+    let latest_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        latest_blockhash,
+    );
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!("Reward center updated in tx: {:?}", &tx_hash);
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anyhow::{anyhow, Context, Result as AnyhowResult};
+use hpl_reward_center::pda::find_reward_center_address;
+use log::info;
+use retry::{delay::Exponential, retry};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use spl_token::{instruction::mint_to, state::Mint};
+
+use crate::{
+    amount::parse_amount,
+    config::{parse_keypair, parse_solana_config},
+};
+
+/// Mints `amount` reward tokens into the reward center's token account so
+/// operators can top up the reward pool after `create-reward-center`,
+/// mirroring the `mint_fungible` pattern used elsewhere in the Holaplex
+/// tooling: fetch the mint, confirm the caller holds mint authority,
+/// create the destination ATA if it's missing, then `mint_to`.
+///
+/// # Errors
+///
+/// Will return `Err` if the pubkeys fail to parse, the mint account
+/// doesn't exist, the caller doesn't hold mint authority, or the
+/// transaction can't be confirmed.
+pub fn process_fund_reward_center(
+    client: RpcClient,
+    keypair_path: Option<PathBuf>,
+    auction_house: String,
+    reward_mint: String,
+    amount: &str,
+    dry_run: bool,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_config()?;
+    let keypair = parse_keypair(&keypair_path, &solana_options)?;
+
+    let auction_house_pubkey = Pubkey::from_str(&auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+    let reward_mint_pubkey =
+        Pubkey::from_str(&reward_mint).context("Failed to parse Pubkey from reward mint string")?;
+
+    let mint_account_data = client
+        .get_account_data(&reward_mint_pubkey)
+        .context("Reward mint account doesn't exist")?;
+
+    let Mint {
+        decimals,
+        mint_authority,
+        ..
+    } = Mint::unpack(&mint_account_data)?;
+
+    let mint_authority =
+        mint_authority.ok_or_else(|| anyhow!("Reward mint doesn't have a mint authority set"))?;
+
+    if mint_authority != keypair.pubkey() {
+        return Err(anyhow!(
+            "Keypair {} doesn't hold mint authority for {} (authority is {})",
+            keypair.pubkey(),
+            reward_mint_pubkey,
+            mint_authority
+        ));
+    }
+
+    let (reward_center_pubkey, _) = find_reward_center_address(&auction_house_pubkey);
+    let reward_center_token_account =
+        get_associated_token_address(&reward_center_pubkey, &reward_mint_pubkey);
+
+    let mut instructions: Vec<Instruction> = vec![];
+
+    if client.get_account(&reward_center_token_account).is_err() {
+        info!("Reward center token account doesn't exist yet. Creating it.");
+
+        instructions.push(create_associated_token_account(
+            &keypair.pubkey(),
+            &reward_center_pubkey,
+            &reward_mint_pubkey,
+        ));
+    }
+
+    let amount_with_decimals = parse_amount(amount, decimals)?;
+
+    instructions.push(mint_to(
+        &spl_token::id(),
+        &reward_mint_pubkey,
+        &reward_center_token_account,
+        &mint_authority,
+        &[],
+        amount_with_decimals,
+    )?);
+
+    if dry_run {
+        crate::commands::print_dry_run(&instructions);
+
+        return Ok(());
+    }
+
+    let latest_blockhash = client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&keypair.pubkey()),
+        &[&keypair],
+        latest_blockhash,
+    );
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!(
+        "Minted {} reward tokens into {} in tx: {:?}",
+        amount, reward_center_token_account, &tx_hash
+    );
+
+    Ok(())
+}
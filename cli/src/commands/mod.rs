@@ -0,0 +1,36 @@
+pub mod accept_offer;
+pub mod buy_listing;
+pub mod create;
+pub mod create_listing;
+pub mod create_offer;
+pub mod delegate_auctioneer;
+pub mod edit;
+pub mod fund_reward_center;
+pub mod withdraw_auction_house;
+
+use log::info;
+use solana_program::instruction::Instruction;
+
+/// Prints the program, accounts and data length of each instruction that
+/// would otherwise be sent, so a `--dry-run` command shows what it would
+/// submit without
+/// spending SOL on fees. Shared by every command module.
+pub fn print_dry_run(instructions: &[Instruction]) {
+    info!(
+        "Dry run: {} instruction(s) would be sent",
+        instructions.len()
+    );
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        info!("Instruction #{}: program {}", index, instruction.program_id);
+
+        for account in &instruction.accounts {
+            info!(
+                "  {} (signer: {}, writable: {})",
+                account.pubkey, account.is_signer, account.is_writable
+            );
+        }
+
+        info!("  data: {} bytes", instruction.data.len());
+    }
+}
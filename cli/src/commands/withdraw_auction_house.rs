@@ -1,9 +1,9 @@
 use std::{path::PathBuf, str::FromStr};
 
 use anchor_lang::AnchorDeserialize;
-use anyhow::{Context, Result as AnyhowResult};
+use anyhow::{anyhow, Context, Result as AnyhowResult};
 use log::info;
-use mpl_auction_house::AuctionHouse;
+use mpl_auction_house::{pda::find_auction_house_treasury_address, AuctionHouse};
 use mpl_auction_house_sdk::{accounts::WithdrawFromTreasuryAccounts, withdraw_from_treasury};
 use retry::{delay::Exponential, retry};
 use solana_client::rpc_client::RpcClient;
@@ -11,20 +11,27 @@ use solana_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubke
 use solana_sdk::{signer::Signer, transaction::Transaction};
 use spl_token::state::Mint;
 
-use crate::config::{parse_keypair, parse_solana_configuration};
+use crate::{
+    amount::parse_amount,
+    config::{parse_keypair, parse_solana_config},
+};
 
 /// # Errors
 ///
 /// Will return `Err` if the following happens
 /// 1. Auction house fails to parse
-/// 2. Withdrawal amount is greater than the treasury balance
+/// 2. Neither `--amount` nor `--all` is given, or the amount overflows
+///    once scaled by the treasury mint's decimals
+/// 3. The treasury balance can't be read when `--all` is passed
 pub fn process_withdraw_auction_house_treasury(
     client: &RpcClient,
     keypair_path: &Option<PathBuf>,
     auction_house: &str,
-    amount: u64,
+    amount: Option<&str>,
+    all: bool,
+    dry_run: bool,
 ) -> AnyhowResult<()> {
-    let solana_options = parse_solana_configuration()?;
+    let solana_options = parse_solana_config()?;
 
     let keypair = parse_keypair(keypair_path, &solana_options)?;
 
@@ -47,8 +54,20 @@ pub fn process_withdraw_auction_house_treasury(
 
     let Mint { decimals, .. } = Mint::unpack(&token_mint_data[..])?;
 
-    let amount_to_withdraw_with_decimals =
-        amount.saturating_mul(10u64.saturating_pow(decimals.into()));
+    let amount_to_withdraw_with_decimals = if all {
+        let (treasury_pubkey, _) = find_auction_house_treasury_address(&auction_house_pubkey);
+
+        client
+            .get_token_account_balance(&treasury_pubkey)
+            .context("Failed to read the auction house treasury's token balance")?
+            .amount
+            .parse()
+            .context("Treasury balance wasn't a valid u64")?
+    } else {
+        let amount = amount.ok_or_else(|| anyhow!("Either --amount or --all is required"))?;
+
+        parse_amount(amount, decimals)?
+    };
 
     let instructions: Vec<Instruction> = vec![withdraw_from_treasury(
         WithdrawFromTreasuryAccounts {
@@ -60,6 +79,12 @@ pub fn process_withdraw_auction_house_treasury(
         amount_to_withdraw_with_decimals,
     )];
 
+    if dry_run {
+        crate::commands::print_dry_run(&instructions);
+
+        return Ok(());
+    }
+
     let latest_blockhash = client.get_latest_blockhash()?;
     let transaction = Transaction::new_signed_with_payer(
         &instructions,
@@ -68,7 +93,10 @@ pub fn process_withdraw_auction_house_treasury(
         latest_blockhash,
     );
 
-    info!("Withdrawing {} tokens from auction house", amount);
+    info!(
+        "Withdrawing {} base units from auction house treasury",
+        amount_to_withdraw_with_decimals
+    );
 
     let tx_hash = retry(
         Exponential::from_millis_with_factor(250, 2.0).take(3),
@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result as AnyhowResult};
+use solana_cli_config::{Config, CONFIG_FILE};
+use solana_sdk::signature::{read_keypair_file, Keypair};
+
+pub struct SolanaConfig {
+    pub json_rpc_url: String,
+    pub keypair_path: PathBuf,
+}
+
+/// Reads `~/.config/solana/cli/config.yml` so commands fall back to the
+/// same keypair the `solana` CLI itself would use when the user doesn't
+/// pass `--keypair`. The config's RPC URL is returned but not used by
+/// `main`, which falls back to devnet when `--url` is absent.
+pub fn parse_solana_config() -> AnyhowResult<SolanaConfig> {
+    let config_file = CONFIG_FILE
+        .as_ref()
+        .context("Unable to locate the default solana CLI config file")?;
+
+    let config = Config::load(config_file).unwrap_or_default();
+
+    Ok(SolanaConfig {
+        json_rpc_url: config.json_rpc_url,
+        keypair_path: PathBuf::from(config.keypair_path),
+    })
+}
+
+/// Resolves the signing keypair, preferring an explicit `--keypair` flag
+/// and falling back to the one in the solana CLI config.
+pub fn parse_keypair(
+    keypair_path: &Option<PathBuf>,
+    solana_options: &SolanaConfig,
+) -> AnyhowResult<Keypair> {
+    let path = keypair_path
+        .clone()
+        .unwrap_or_else(|| solana_options.keypair_path.clone());
+
+    read_keypair_file(&path)
+        .map_err(|err| anyhow::anyhow!("Failed to read keypair file {:?}: {}", path, err))
+}
@@ -0,0 +1,352 @@
+mod amount;
+mod commands;
+mod config;
+mod schema;
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+use hpl_reward_center::reward_centers::delegate::DelegateScope;
+use solana_client::rpc_client::RpcClient;
+
+use crate::{
+    commands::{
+        accept_offer::process_accept_offer, buy_listing::process_buy_listing,
+        create::process_create_reward_center, create_listing::process_create_listing,
+        create_offer::process_create_offer, delegate_auctioneer::process_delegate_auctioneer,
+        edit::process_edit_reward_center, fund_reward_center::process_fund_reward_center,
+        withdraw_auction_house::process_withdraw_auction_house_treasury,
+    },
+    schema::{EditRewardCenterParams, PayoutOperation},
+};
+
+/// Command-line interface for operating a Holaplex reward center on top
+/// of a Metaplex auction house.
+#[derive(Parser)]
+#[command(name = "hpl-reward-center", version, about)]
+struct Cli {
+    /// JSON RPC URL of the cluster to operate against.
+    #[arg(long, global = true)]
+    url: Option<String>,
+
+    /// Path to the signing keypair. Falls back to the solana CLI config.
+    #[arg(long, global = true)]
+    keypair: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a reward center for an auction house, creating the auction
+    /// house and reward mint too if they aren't provided.
+    CreateRewardCenter {
+        /// Path to a JSON file describing the reward rules.
+        #[arg(long, default_value = "reward_center_config.json")]
+        config_file: PathBuf,
+
+        /// Existing auction house to attach the reward center to.
+        #[arg(long)]
+        auction_house: Option<String>,
+
+        /// Existing reward mint to use instead of creating a new one.
+        #[arg(long)]
+        mint_rewards: Option<String>,
+
+        /// Print the instruction accounts instead of sending the transaction.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Edit a reward center's reward rules.
+    EditRewardCenter {
+        #[arg(long)]
+        auction_house: String,
+
+        #[arg(long)]
+        payout_numeral: u16,
+
+        #[arg(long)]
+        seller_reward_payout_basis_points: u16,
+
+        #[arg(long, value_enum)]
+        mathematical_operand: MathematicalOperandArg,
+
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Withdraw funds from the auction house's treasury.
+    WithdrawRewardCenterFunds {
+        #[arg(long)]
+        auction_house: String,
+
+        /// Human-readable amount, e.g. `1.25`. Conflicts with `--all`.
+        #[arg(long, conflicts_with = "all")]
+        amount: Option<String>,
+
+        /// Withdraw the treasury's entire current balance.
+        #[arg(long)]
+        all: bool,
+
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Mint reward tokens into the reward center's treasury.
+    FundRewardCenter {
+        #[arg(long)]
+        auction_house: String,
+
+        #[arg(long)]
+        reward_mint: String,
+
+        /// Human-readable amount, e.g. `1.25`.
+        #[arg(long)]
+        amount: String,
+
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Delegate (or re-delegate) auctioneer scopes to the reward center.
+    DelegateAuctioneer {
+        #[arg(long)]
+        auction_house: String,
+
+        /// Scopes to grant (or revoke with `--revoke`), e.g.
+        /// `--scopes buy --scopes execute-sale`.
+        #[arg(long = "scopes", value_enum, required = true)]
+        scopes: Vec<DelegateScopeArg>,
+
+        /// Clear the listed scopes instead of granting them.
+        #[arg(long)]
+        revoke: bool,
+
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// List an NFT for sale through the reward center.
+    CreateListing {
+        #[arg(long)]
+        auction_house: String,
+
+        #[arg(long)]
+        token_mint: String,
+
+        /// Human-readable price, e.g. `1.25`.
+        #[arg(long)]
+        price: String,
+
+        #[arg(long, default_value_t = 1)]
+        token_size: u64,
+
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Buy a listing.
+    BuyListing {
+        #[arg(long)]
+        auction_house: String,
+
+        #[arg(long)]
+        listing: String,
+
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Make an offer on an NFT through the reward center.
+    CreateOffer {
+        #[arg(long)]
+        auction_house: String,
+
+        #[arg(long)]
+        token_mint: String,
+
+        /// Human-readable price, e.g. `1.25`.
+        #[arg(long)]
+        price: String,
+
+        #[arg(long, default_value_t = 1)]
+        token_size: u64,
+
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Accept an offer as the seller.
+    AcceptOffer {
+        #[arg(long)]
+        auction_house: String,
+
+        #[arg(long)]
+        offer: String,
+
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Clone, clap::ValueEnum)]
+enum MathematicalOperandArg {
+    Divide,
+    Multiple,
+}
+
+impl From<MathematicalOperandArg> for PayoutOperation {
+    fn from(value: MathematicalOperandArg) -> Self {
+        match value {
+            MathematicalOperandArg::Divide => PayoutOperation::Divide,
+            MathematicalOperandArg::Multiple => PayoutOperation::Multiple,
+        }
+    }
+}
+
+#[derive(Clone, clap::ValueEnum)]
+enum DelegateScopeArg {
+    Buy,
+    Sell,
+    Cancel,
+    ExecuteSale,
+    Deposit,
+    Withdraw,
+}
+
+impl From<DelegateScopeArg> for DelegateScope {
+    fn from(value: DelegateScopeArg) -> Self {
+        match value {
+            DelegateScopeArg::Buy => DelegateScope::Buy,
+            DelegateScopeArg::Sell => DelegateScope::Sell,
+            DelegateScopeArg::Cancel => DelegateScope::Cancel,
+            DelegateScopeArg::ExecuteSale => DelegateScope::ExecuteSale,
+            DelegateScopeArg::Deposit => DelegateScope::Deposit,
+            DelegateScopeArg::Withdraw => DelegateScope::Withdraw,
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    env_logger::init();
+
+    let cli = Cli::parse();
+    let client = RpcClient::new(
+        cli.url
+            .unwrap_or_else(|| "https://api.devnet.solana.com".to_owned()),
+    );
+
+    match cli.command {
+        Command::CreateRewardCenter {
+            config_file,
+            auction_house,
+            mint_rewards,
+            dry_run,
+        } => process_create_reward_center(
+            client,
+            cli.keypair,
+            config_file,
+            auction_house,
+            mint_rewards,
+            dry_run,
+        ),
+        Command::EditRewardCenter {
+            auction_house,
+            payout_numeral,
+            seller_reward_payout_basis_points,
+            mathematical_operand,
+            dry_run,
+        } => process_edit_reward_center(
+            client,
+            cli.keypair,
+            auction_house,
+            EditRewardCenterParams {
+                mathematical_operand: mathematical_operand.into(),
+                payout_numeral,
+                seller_reward_payout_basis_points,
+            },
+            dry_run,
+        ),
+        Command::WithdrawRewardCenterFunds {
+            auction_house,
+            amount,
+            all,
+            dry_run,
+        } => process_withdraw_auction_house_treasury(
+            &client,
+            &cli.keypair,
+            &auction_house,
+            amount.as_deref(),
+            all,
+            dry_run,
+        ),
+        Command::FundRewardCenter {
+            auction_house,
+            reward_mint,
+            amount,
+            dry_run,
+        } => process_fund_reward_center(
+            client,
+            cli.keypair,
+            auction_house,
+            reward_mint,
+            &amount,
+            dry_run,
+        ),
+        Command::DelegateAuctioneer {
+            auction_house,
+            scopes,
+            revoke,
+            dry_run,
+        } => process_delegate_auctioneer(
+            &client,
+            &cli.keypair,
+            &auction_house,
+            scopes.into_iter().map(Into::into).collect(),
+            revoke,
+            dry_run,
+        ),
+        Command::CreateListing {
+            auction_house,
+            token_mint,
+            price,
+            token_size,
+            dry_run,
+        } => process_create_listing(
+            client,
+            cli.keypair,
+            auction_house,
+            token_mint,
+            &price,
+            token_size,
+            dry_run,
+        ),
+        Command::BuyListing {
+            auction_house,
+            listing,
+            dry_run,
+        } => process_buy_listing(client, cli.keypair, auction_house, listing, dry_run),
+        Command::CreateOffer {
+            auction_house,
+            token_mint,
+            price,
+            token_size,
+            dry_run,
+        } => process_create_offer(
+            client,
+            cli.keypair,
+            auction_house,
+            token_mint,
+            &price,
+            token_size,
+            dry_run,
+        ),
+        Command::AcceptOffer {
+            auction_house,
+            offer,
+            dry_run,
+        } => process_accept_offer(client, cli.keypair, auction_house, offer, dry_run),
+    }
+}
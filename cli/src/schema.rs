@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Mirrors `hpl_reward_center::state::PayoutOperation` so config files can
+/// be deserialized with `serde_json` without pulling the anchor program
+/// crate's `AnchorDeserialize` impl into the CLI's JSON config path.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub enum PayoutOperation {
+    Divide,
+    Multiple,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CreateRewardCenterParams {
+    pub mathematical_operand: PayoutOperation,
+    pub payout_numeral: u16,
+    pub seller_reward_payout_basis_points: u16,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct EditRewardCenterParams {
+    pub mathematical_operand: PayoutOperation,
+    pub payout_numeral: u16,
+    pub seller_reward_payout_basis_points: u16,
+}
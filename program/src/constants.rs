@@ -0,0 +1,19 @@
+pub const REWARD_CENTER_PREFIX: &str = "reward_center";
+pub const LISTING_PREFIX: &str = "listing";
+pub const OFFER_PREFIX: &str = "offer";
+
+/// Seeds for the indexer-facing receipt PDAs. Unlike `Listing`/`Offer`,
+/// these are never closed, so off-chain services can replay marketplace
+/// activity without having witnessed every transaction.
+pub const LISTING_RECEIPT_PREFIX: &str = "listing_receipt";
+pub const BID_RECEIPT_PREFIX: &str = "bid_receipt";
+pub const PURCHASE_RECEIPT_PREFIX: &str = "purchase_receipt";
+
+/// Seed prefix for a reward center signer PDA. No instruction or `pda`
+/// helper in this crate derives an address from it; auction house CPIs
+/// are signed by the reward center PDA itself (`REWARD_CENTER_PREFIX`)
+/// as the auctioneer authority.
+pub const REWARD_CENTER_SIGNER_PREFIX: &str = "reward_center_signer";
+
+/// Basis points denominator used throughout the reward rule math.
+pub const HUNDRED_PERCENT_BASIS_POINTS: u16 = 10_000;
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum RewardCenterError {
+    #[msg("Public key mismatch")]
+    PublicKeyMismatch,
+
+    #[msg("Invalid payout numeral for the selected mathematical operand")]
+    InvalidPayoutNumeral,
+
+    #[msg("Seller reward payout basis points must be less than or equal to 10000")]
+    InvalidBasisPoints,
+
+    #[msg("Token account doesn't have enough tokens")]
+    InsufficientFunds,
+
+    #[msg("Not enough SOL to pay for this listing or offer")]
+    NotEnoughFundsToPayForRewards,
+
+    #[msg("Numerical overflow error")]
+    NumericalOverflow,
+
+    #[msg("Token standard is missing from the metadata account")]
+    MissingTokenStandard,
+
+    #[msg("Token record account is required for programmable NFTs")]
+    MissingTokenRecord,
+
+    #[msg("Authorization rules account is required for this programmable NFT")]
+    MissingAuthorizationRules,
+
+    #[msg("Authorization rules program is required for this programmable NFT")]
+    MissingAuthorizationRulesProgram,
+
+    #[msg("Reward center does not have the required authority scope delegated")]
+    MissingAuctioneerScope,
+}
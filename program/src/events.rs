@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+/// Emitted by `listings::create`. Mirrors the fields recorded in `ListingReceipt`.
+#[event]
+pub struct ListingCreatedEvent {
+    pub listing: Pubkey,
+    pub listing_receipt: Pubkey,
+    pub reward_center: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+}
+
+/// Emitted by `listings::close`.
+#[event]
+pub struct ListingCanceledEvent {
+    pub listing: Pubkey,
+    pub listing_receipt: Pubkey,
+}
+
+/// Emitted by `offers::create`. Mirrors the fields recorded in `BidReceipt`.
+#[event]
+pub struct BidCreatedEvent {
+    pub offer: Pubkey,
+    pub bid_receipt: Pubkey,
+    pub reward_center: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+}
+
+/// Emitted by `offers::close`.
+#[event]
+pub struct BidCanceledEvent {
+    pub offer: Pubkey,
+    pub bid_receipt: Pubkey,
+}
+
+/// Emitted by `listings::buy` and `offers::accept` once `execute_sale`
+/// succeeds. Mirrors the fields recorded in `PurchaseReceipt`.
+#[event]
+pub struct PurchaseEvent {
+    pub purchase_receipt: Pubkey,
+    pub reward_center: Pubkey,
+    pub metadata: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub seller_reward_payout: u64,
+}
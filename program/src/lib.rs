@@ -1,5 +1,6 @@
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod listings;
 pub mod metaplex_cpi;
 pub mod offers;
@@ -12,7 +13,7 @@ use anchor_lang::prelude::*;
 use crate::{
     listings::{buy::*, close::*, create::*, update::*},
     offers::{accept::*, close::*, create::*},
-    reward_centers::{create::*, edit::*, withdraw::*},
+    reward_centers::{create::*, delegate::*, edit::*, withdraw::*},
 };
 
 declare_id!("RwDDvPp7ta9qqUwxbBfShsNreBaSsKvFcHzMxfBC3Ki");
@@ -42,6 +43,13 @@ pub mod reward_center {
         reward_centers::withdraw::handler(ctx, withdraw_reward_center_funds_params)
     }
 
+    pub fn delegate_auctioneer(
+        ctx: Context<DelegateAuctioneer>,
+        delegate_auctioneer_params: DelegateAuctioneerParams,
+    ) -> Result<()> {
+        reward_centers::delegate::handler(ctx, delegate_auctioneer_params)
+    }
+
     pub fn create_listing(
         ctx: Context<CreateListing>,
         create_listing_params: CreateListingParams,
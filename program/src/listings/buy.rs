@@ -0,0 +1,322 @@
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_auction_house::{
+    cpi::accounts::AuctioneerExecuteSale, program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse,
+};
+use mpl_token_auth_rules::payload::{Payload, PayloadType};
+use mpl_token_metadata::state::{Metadata, TokenStandard};
+
+use crate::{
+    constants::{LISTING_RECEIPT_PREFIX, PURCHASE_RECEIPT_PREFIX},
+    errors::RewardCenterError,
+    events::PurchaseEvent,
+    metaplex_cpi::{execute_auctioneer_sale, invoke_auth_rules_validate, is_programmable},
+    state::{Listing, ListingReceipt, PurchaseReceipt, RewardCenter},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BuyListingParams {
+    pub escrow_payment_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub program_as_signer_bump: u8,
+}
+
+/// Buys a listing through the reward center's delegated auctioneer
+/// authority. Classic SPL-token NFTs take the cheap path straight through
+/// `execute_sale`; `TokenStandard::ProgrammableNonFungible` assets
+/// additionally require the owner/destination token-record PDAs, the
+/// mint's authorization-rules account, the `mpl_token_auth_rules` program
+/// and the instructions sysvar, all passed as remaining accounts in that
+/// order so the classic path doesn't pay for accounts it never touches.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyListing<'info>>,
+    BuyListingParams {
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+    }: BuyListingParams,
+) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let metadata = Metadata::deserialize(&mut &ctx.accounts.metadata.data.borrow()[..])?;
+
+    require!(
+        ctx.accounts.reward_center.auctioneer_scopes.execute_sale,
+        RewardCenterError::MissingAuctioneerScope
+    );
+
+    let auctioneer_authority_seeds = &[
+        crate::constants::REWARD_CENTER_PREFIX.as_bytes(),
+        ctx.accounts.auction_house.key().as_ref(),
+        &[ctx.accounts.reward_center.bump],
+    ];
+
+    let execute_sale_accounts = AuctioneerExecuteSale {
+        buyer: ctx.accounts.buyer.to_account_info(),
+        seller: ctx.accounts.seller.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        seller_payment_receipt_account: ctx
+            .accounts
+            .seller_payment_receipt_account
+            .to_account_info(),
+        buyer_receipt_token_account: ctx.accounts.buyer_receipt_token_account.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        auction_house_treasury: ctx.accounts.auction_house_treasury.to_account_info(),
+        buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+        seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+        free_trade_state: ctx.accounts.free_trade_state.to_account_info(),
+        auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        ata_program: ctx.accounts.ata_program.to_account_info(),
+        program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    if is_programmable(&metadata) {
+        let remaining_accounts = ctx.remaining_accounts;
+
+        let [owner_token_record, destination_token_record, authorization_rules, authorization_rules_program, instructions_sysvar]: &[AccountInfo<'info>; 5] =
+            remaining_accounts
+                .get(0..5)
+                .ok_or(RewardCenterError::MissingTokenRecord)?
+                .try_into()
+                .map_err(|_| RewardCenterError::MissingTokenRecord)?;
+
+        require_keys_eq!(
+            *instructions_sysvar.key,
+            sysvar::instructions::ID,
+            RewardCenterError::MissingTokenRecord
+        );
+
+        let rule_set = match &metadata.programmable_config {
+            Some(mpl_token_metadata::state::ProgrammableConfig::V1 { rule_set }) => *rule_set,
+            None => return Err(RewardCenterError::MissingAuthorizationRules.into()),
+        };
+
+        if let Some(rule_set) = rule_set {
+            require_keys_eq!(
+                *authorization_rules.key,
+                rule_set,
+                RewardCenterError::MissingAuthorizationRules
+            );
+            require_keys_eq!(
+                *authorization_rules_program.key,
+                mpl_token_auth_rules::id(),
+                RewardCenterError::MissingAuthorizationRulesProgram
+            );
+        }
+
+        let payload =
+            Payload::from([("Amount".to_owned(), PayloadType::Number(listing.token_size))]);
+
+        msg!(
+            "Executing programmable NFT sale with token record {} -> {}",
+            owner_token_record.key,
+            destination_token_record.key
+        );
+
+        if rule_set.is_some() {
+            let validate_data = mpl_token_auth_rules::instruction::RuleSetInstruction::Validate(
+                mpl_token_auth_rules::instruction::ValidateArgs {
+                    operation: "Transfer".to_owned(),
+                    payload,
+                    update_rule_state: false,
+                    rule_set_revision: None,
+                },
+            )
+            .try_to_vec()
+            .map_err(|_| RewardCenterError::MissingAuthorizationRules)?;
+
+            invoke_auth_rules_validate(
+                authorization_rules_program,
+                &[
+                    ctx.accounts.buyer.to_account_info(),
+                    authorization_rules.clone(),
+                    ctx.accounts.token_mint.to_account_info(),
+                ],
+                validate_data,
+                &[],
+            )?;
+        }
+
+        let remaining_accounts = [
+            owner_token_record.clone(),
+            destination_token_record.clone(),
+            authorization_rules.clone(),
+            authorization_rules_program.clone(),
+            instructions_sysvar.clone(),
+        ];
+
+        execute_auctioneer_sale(
+            &ctx.accounts.auction_house_program,
+            execute_sale_accounts,
+            &remaining_accounts,
+            auctioneer_authority_seeds,
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            listing.price,
+            listing.token_size,
+        )?;
+    } else {
+        execute_auctioneer_sale(
+            &ctx.accounts.auction_house_program,
+            execute_sale_accounts,
+            &[],
+            auctioneer_authority_seeds,
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            listing.price,
+            listing.token_size,
+        )?;
+    }
+
+    let clock = Clock::get()?;
+    let seller_reward_payout = ctx
+        .accounts
+        .reward_center
+        .reward_rules
+        .payout(listing.price)?;
+
+    ctx.accounts.listing_receipt.purchased_at = Some(clock.unix_timestamp);
+
+    let purchase_receipt = &mut ctx.accounts.purchase_receipt;
+    purchase_receipt.reward_center = ctx.accounts.reward_center.key();
+    purchase_receipt.metadata = ctx.accounts.metadata.key();
+    purchase_receipt.seller = ctx.accounts.seller.key();
+    purchase_receipt.buyer = ctx.accounts.buyer.key();
+    purchase_receipt.price = listing.price;
+    purchase_receipt.token_size = listing.token_size;
+    purchase_receipt.seller_reward_payout = seller_reward_payout;
+    purchase_receipt.bump = *ctx.bumps.get("purchase_receipt").unwrap();
+    purchase_receipt.created_at = clock.unix_timestamp;
+
+    emit!(PurchaseEvent {
+        purchase_receipt: purchase_receipt.key(),
+        reward_center: purchase_receipt.reward_center,
+        metadata: purchase_receipt.metadata,
+        seller: purchase_receipt.seller,
+        buyer: purchase_receipt.buyer,
+        price: purchase_receipt.price,
+        token_size: purchase_receipt.token_size,
+        seller_reward_payout,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BuyListing<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: validated by the auction house program during `execute_sale`
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    /// CHECK: deserialized in the handler to branch on `TokenStandard`
+    pub metadata: UncheckedAccount<'info>,
+
+    pub treasury_mint: Account<'info, Mint>,
+
+    /// CHECK: validated by the auction house program
+    #[account(mut)]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the auction house program
+    #[account(mut)]
+    pub seller_payment_receipt_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub buyer_receipt_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: the auction house's own authority; validated by the auction
+    /// house program against `auction_house.authority` during
+    /// `auctioneer_execute_sale`
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [crate::constants::REWARD_CENTER_PREFIX.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump,
+        has_one = auction_house @ RewardCenterError::PublicKeyMismatch,
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: the reward center's delegated auctioneer registration,
+    /// created by `reward_centers::delegate`; validated by the auction
+    /// house program
+    #[account(
+        seeds = [b"auctioneer", auction_house.key().as_ref(), reward_center.key().as_ref()],
+        bump,
+        seeds::program = auction_house_program.key(),
+    )]
+    pub ah_auctioneer_pda: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the auction house program
+    #[account(mut)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the auction house program
+    #[account(mut)]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the auction house program
+    #[account(mut)]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the auction house program
+    #[account(mut)]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the auction house program
+    #[account(mut)]
+    pub free_trade_state: UncheckedAccount<'info>,
+
+    #[account(mut, close = buyer)]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_RECEIPT_PREFIX.as_bytes(), listing.key().as_ref()],
+        bump = listing_receipt.bump,
+    )]
+    pub listing_receipt: Box<Account<'info, ListingReceipt>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = PurchaseReceipt::SIZE,
+        seeds = [
+            PURCHASE_RECEIPT_PREFIX.as_bytes(),
+            seller_trade_state.key().as_ref(),
+            buyer_trade_state.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub purchase_receipt: Box<Account<'info, PurchaseReceipt>>,
+
+    /// CHECK: validated by the auction house program
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub token_program: Program<'info, Token>,
+    pub ata_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::LISTING_RECEIPT_PREFIX,
+    errors::RewardCenterError,
+    events::ListingCanceledEvent,
+    state::{Listing, ListingReceipt},
+};
+
+pub fn handler(ctx: Context<CloseListing>) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.listing.canceled_at = Some(clock.unix_timestamp);
+    ctx.accounts.listing_receipt.canceled_at = Some(clock.unix_timestamp);
+
+    emit!(ListingCanceledEvent {
+        listing: ctx.accounts.listing.key(),
+        listing_receipt: ctx.accounts.listing_receipt.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseListing<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        close = wallet,
+        constraint = listing.seller == wallet.key() @ RewardCenterError::PublicKeyMismatch,
+        constraint = listing.purchase_ticket.is_none() @ RewardCenterError::PublicKeyMismatch,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_RECEIPT_PREFIX.as_bytes(), listing.key().as_ref()],
+        bump = listing_receipt.bump,
+    )]
+    pub listing_receipt: Box<Account<'info, ListingReceipt>>,
+}
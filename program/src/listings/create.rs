@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_auction_house::AuctionHouse;
+use mpl_token_metadata::state::Metadata;
+
+use crate::{
+    constants::{LISTING_PREFIX, LISTING_RECEIPT_PREFIX},
+    errors::RewardCenterError,
+    events::ListingCreatedEvent,
+    state::{Listing, ListingReceipt, RewardCenter},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateListingParams {
+    pub price: u64,
+    pub token_size: u64,
+}
+
+pub fn handler(
+    ctx: Context<CreateListing>,
+    CreateListingParams { price, token_size }: CreateListingParams,
+) -> Result<()> {
+    require_keys_eq!(
+        *ctx.accounts.metadata.owner,
+        mpl_token_metadata::id(),
+        RewardCenterError::PublicKeyMismatch
+    );
+    let metadata = unpack_metadata(&ctx.accounts.metadata.data.borrow()[..])?;
+    require_keys_eq!(
+        metadata.mint,
+        ctx.accounts.token_mint.key(),
+        RewardCenterError::PublicKeyMismatch
+    );
+
+    let listing = &mut ctx.accounts.listing;
+    let clock = Clock::get()?;
+
+    listing.reward_center = ctx.accounts.reward_center.key();
+    listing.seller = ctx.accounts.wallet.key();
+    listing.metadata = ctx.accounts.metadata.key();
+    listing.price = price;
+    listing.token_size = token_size;
+    listing.bump = *ctx.bumps.get("listing").unwrap();
+    listing.created_at = clock.unix_timestamp;
+    listing.canceled_at = None;
+    listing.purchase_ticket = None;
+
+    let listing_receipt = &mut ctx.accounts.listing_receipt;
+    listing_receipt.listing = listing.key();
+    listing_receipt.reward_center = listing.reward_center;
+    listing_receipt.seller = listing.seller;
+    listing_receipt.metadata = listing.metadata;
+    listing_receipt.price = listing.price;
+    listing_receipt.token_size = listing.token_size;
+    listing_receipt.bump = *ctx.bumps.get("listing_receipt").unwrap();
+    listing_receipt.created_at = listing.created_at;
+    listing_receipt.canceled_at = None;
+    listing_receipt.purchased_at = None;
+
+    emit!(ListingCreatedEvent {
+        listing: listing.key(),
+        listing_receipt: listing_receipt.key(),
+        reward_center: listing.reward_center,
+        seller: listing.seller,
+        metadata: listing.metadata,
+        price: listing.price,
+        token_size: listing.token_size,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateListing<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: the handler checks that it is owned by the token metadata
+    /// program and that its mint is `token_mint`
+    pub metadata: UncheckedAccount<'info>,
+
+    pub token_account: Account<'info, TokenAccount>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    #[account(
+        init,
+        payer = wallet,
+        space = Listing::SIZE,
+        seeds = [
+            LISTING_PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        init,
+        payer = wallet,
+        space = ListingReceipt::SIZE,
+        seeds = [LISTING_RECEIPT_PREFIX.as_bytes(), listing.key().as_ref()],
+        bump,
+    )]
+    pub listing_receipt: Box<Account<'info, ListingReceipt>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn unpack_metadata(data: &[u8]) -> Result<Metadata> {
+    Ok(Metadata::deserialize(&mut &data[..])?)
+}
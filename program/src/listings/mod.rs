@@ -0,0 +1,4 @@
+pub mod buy;
+pub mod close;
+pub mod create;
+pub mod update;
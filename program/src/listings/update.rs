@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::RewardCenterError, state::Listing};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateListingParams {
+    pub new_price: u64,
+}
+
+pub fn handler(
+    ctx: Context<UpdateListing>,
+    UpdateListingParams { new_price }: UpdateListingParams,
+) -> Result<()> {
+    ctx.accounts.listing.price = new_price;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateListing<'info> {
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = listing.seller == wallet.key() @ RewardCenterError::PublicKeyMismatch,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+}
@@ -0,0 +1,90 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use mpl_auction_house::{
+    cpi::{accounts::AuctioneerExecuteSale, auctioneer_execute_sale},
+    program::AuctionHouse as AuctionHouseProgram,
+};
+
+/// Thin wrapper around the auction house's `auctioneer_execute_sale` CPI
+/// so the `listings::buy` and `offers::accept` handlers share one call
+/// site for both the classic SPL token path and the programmable NFT
+/// path. Unlike the plain `execute_sale` instruction (which requires its
+/// `authority` account to equal `auction_house.authority` directly), this
+/// is the variant the auction house exposes for delegated auctioneers:
+/// it accepts the reward center PDA as `auctioneer_authority`, signed via
+/// `auctioneer_authority_seeds`, and requires the `ah_auctioneer_pda`
+/// registered by `reward_centers::delegate` with the `ExecuteSale` scope.
+/// `remaining_accounts` is forwarded as-is so the programmable-NFT path
+/// can hand the owner/destination token-record, authorization-rules, and
+/// instructions-sysvar accounts through to the CPI without the classic
+/// path paying for them.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_auctioneer_sale<'info>(
+    auction_house_program: &Program<'info, AuctionHouseProgram>,
+    accounts: AuctioneerExecuteSale<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    auctioneer_authority_seeds: &[&[u8]],
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    let mut cpi_ctx = CpiContext::new_with_signer(
+        auction_house_program.to_account_info(),
+        accounts,
+        &[auctioneer_authority_seeds],
+    );
+
+    if !remaining_accounts.is_empty() {
+        cpi_ctx = cpi_ctx.with_remaining_accounts(remaining_accounts.to_vec());
+    }
+
+    auctioneer_execute_sale(
+        cpi_ctx,
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        buyer_price,
+        token_size,
+    )
+}
+
+/// Returns `true` when the mint's metadata declares it as a
+/// `TokenStandard::ProgrammableNonFungible`, which requires the additional
+/// token-record / auth-rules accounts on transfer.
+pub fn is_programmable(metadata: &mpl_token_metadata::state::Metadata) -> bool {
+    matches!(
+        metadata.token_standard,
+        Some(mpl_token_metadata::state::TokenStandard::ProgrammableNonFungible)
+    )
+}
+
+/// Invokes `mpl_token_auth_rules`' `validate` instruction directly via
+/// `invoke_signed` since the auth-rules program doesn't expose an anchor
+/// CPI client; used when we need to pre-flight a transfer payload before
+/// handing it to the auction house's `execute_sale`.
+pub fn invoke_auth_rules_validate<'info>(
+    auth_rules_program: &AccountInfo<'info>,
+    account_infos: &[AccountInfo<'info>],
+    data: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = anchor_lang::solana_program::instruction::Instruction {
+        program_id: auth_rules_program.key(),
+        accounts: account_infos
+            .iter()
+            .map(
+                |account| anchor_lang::solana_program::instruction::AccountMeta {
+                    pubkey: *account.key,
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                },
+            )
+            .collect(),
+        data,
+    };
+
+    invoke_signed(&ix, account_infos, signer_seeds)?;
+
+    Ok(())
+}
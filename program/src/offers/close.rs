@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::BID_RECEIPT_PREFIX,
+    errors::RewardCenterError,
+    events::BidCanceledEvent,
+    state::{BidReceipt, Offer},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CloseOfferParams {}
+
+pub fn handler(ctx: Context<CloseOffer>, _params: CloseOfferParams) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.offer.canceled_at = Some(clock.unix_timestamp);
+    ctx.accounts.bid_receipt.canceled_at = Some(clock.unix_timestamp);
+
+    emit!(BidCanceledEvent {
+        offer: ctx.accounts.offer.key(),
+        bid_receipt: ctx.accounts.bid_receipt.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseOffer<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        close = wallet,
+        constraint = offer.buyer == wallet.key() @ RewardCenterError::PublicKeyMismatch,
+        constraint = offer.purchase_ticket.is_none() @ RewardCenterError::PublicKeyMismatch,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    #[account(
+        mut,
+        seeds = [BID_RECEIPT_PREFIX.as_bytes(), offer.key().as_ref()],
+        bump = bid_receipt.bump,
+    )]
+    pub bid_receipt: Box<Account<'info, BidReceipt>>,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BID_RECEIPT_PREFIX, OFFER_PREFIX},
+    errors::RewardCenterError,
+    events::BidCreatedEvent,
+    listings::create::unpack_metadata,
+    state::{BidReceipt, Offer, RewardCenter},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateOfferParams {
+    pub price: u64,
+    pub token_size: u64,
+}
+
+pub fn handler(
+    ctx: Context<CreateOffer>,
+    CreateOfferParams { price, token_size }: CreateOfferParams,
+) -> Result<()> {
+    require_keys_eq!(
+        *ctx.accounts.metadata.owner,
+        mpl_token_metadata::id(),
+        RewardCenterError::PublicKeyMismatch
+    );
+    unpack_metadata(&ctx.accounts.metadata.data.borrow()[..])?;
+
+    let offer = &mut ctx.accounts.offer;
+    let clock = Clock::get()?;
+
+    offer.reward_center = ctx.accounts.reward_center.key();
+    offer.buyer = ctx.accounts.wallet.key();
+    offer.metadata = ctx.accounts.metadata.key();
+    offer.price = price;
+    offer.token_size = token_size;
+    offer.bump = *ctx.bumps.get("offer").unwrap();
+    offer.created_at = clock.unix_timestamp;
+    offer.canceled_at = None;
+    offer.purchase_ticket = None;
+
+    let bid_receipt = &mut ctx.accounts.bid_receipt;
+    bid_receipt.offer = offer.key();
+    bid_receipt.reward_center = offer.reward_center;
+    bid_receipt.buyer = offer.buyer;
+    bid_receipt.metadata = offer.metadata;
+    bid_receipt.price = offer.price;
+    bid_receipt.token_size = offer.token_size;
+    bid_receipt.bump = *ctx.bumps.get("bid_receipt").unwrap();
+    bid_receipt.created_at = offer.created_at;
+    bid_receipt.canceled_at = None;
+    bid_receipt.purchased_at = None;
+
+    emit!(BidCreatedEvent {
+        offer: offer.key(),
+        bid_receipt: bid_receipt.key(),
+        reward_center: offer.reward_center,
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        price: offer.price,
+        token_size: offer.token_size,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateOffer<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: the handler checks that it is owned by the token metadata
+    /// program and deserializes as `Metadata`
+    pub metadata: UncheckedAccount<'info>,
+
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    #[account(
+        init,
+        payer = wallet,
+        space = Offer::SIZE,
+        seeds = [
+            OFFER_PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    #[account(
+        init,
+        payer = wallet,
+        space = BidReceipt::SIZE,
+        seeds = [BID_RECEIPT_PREFIX.as_bytes(), offer.key().as_ref()],
+        bump,
+    )]
+    pub bid_receipt: Box<Account<'info, BidReceipt>>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    BID_RECEIPT_PREFIX, LISTING_PREFIX, LISTING_RECEIPT_PREFIX, OFFER_PREFIX,
+    PURCHASE_RECEIPT_PREFIX, REWARD_CENTER_PREFIX,
+};
+
+pub fn find_reward_center_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REWARD_CENTER_PREFIX.as_bytes(), auction_house.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn find_listing_address(
+    seller: &Pubkey,
+    metadata: &Pubkey,
+    reward_center: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LISTING_PREFIX.as_bytes(),
+            seller.as_ref(),
+            metadata.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_offer_address(
+    buyer: &Pubkey,
+    metadata: &Pubkey,
+    reward_center: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            OFFER_PREFIX.as_bytes(),
+            buyer.as_ref(),
+            metadata.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_listing_receipt_address(listing: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LISTING_RECEIPT_PREFIX.as_bytes(), listing.as_ref()],
+        &crate::ID,
+    )
+}
+
+pub fn find_bid_receipt_address(offer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_RECEIPT_PREFIX.as_bytes(), offer.as_ref()], &crate::ID)
+}
+
+/// Seeded the same way as Metaplex's auction house purchase receipts, off
+/// the pair of trade states that `execute_sale` closes, so it stays unique
+/// per fill regardless of whether the fill originated from a listing or
+/// an offer.
+pub fn find_purchase_receipt_address(
+    seller_trade_state: &Pubkey,
+    buyer_trade_state: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PURCHASE_RECEIPT_PREFIX.as_bytes(),
+            seller_trade_state.as_ref(),
+            buyer_trade_state.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_auction_house::AuctionHouse;
+
+use crate::{
+    constants::REWARD_CENTER_PREFIX,
+    errors::RewardCenterError,
+    state::{RewardCenter, RewardRules},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateRewardCenterParams {
+    pub reward_rules: RewardRules,
+}
+
+pub fn handler(
+    ctx: Context<CreateRewardCenter>,
+    CreateRewardCenterParams { reward_rules }: CreateRewardCenterParams,
+) -> Result<()> {
+    require!(
+        reward_rules.seller_reward_payout_basis_points <= 10_000,
+        RewardCenterError::InvalidBasisPoints
+    );
+    require!(
+        reward_rules.payout_numeral > 0,
+        RewardCenterError::InvalidPayoutNumeral
+    );
+
+    let reward_center = &mut ctx.accounts.reward_center;
+
+    reward_center.token_mint = ctx.accounts.mint.key();
+    reward_center.auction_house = ctx.accounts.auction_house.key();
+    reward_center.bump = *ctx.bumps.get("reward_center").unwrap();
+    reward_center.reward_rules = reward_rules;
+    reward_center.auctioneer_scopes = crate::state::AuctioneerScopes::default();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateRewardCenter<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = reward_center,
+    )]
+    pub reward_center_reward_token_account: Account<'info, TokenAccount>,
+
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    pub auction_house_treasury_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = wallet,
+        space = RewardCenter::SIZE,
+        seeds = [REWARD_CENTER_PREFIX.as_bytes(), auction_house.key().as_ref()],
+        bump,
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
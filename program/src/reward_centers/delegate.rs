@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use mpl_auction_house::{
+    cpi::{accounts::AuctioneerDelegate, delegate_auctioneer},
+    pda::find_auctioneer_pda,
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse, AuthorityScope,
+};
+
+use crate::{
+    constants::REWARD_CENTER_PREFIX, errors::RewardCenterError, state::AuctioneerScopes,
+    state::RewardCenter,
+};
+
+/// Scopes the reward center's auctioneer PDA can be granted on an auction
+/// house. Mirrors `mpl_auction_house::AuthorityScope`, minus `PublicBuy`,
+/// which the reward center never needs since it always buys on a seller's
+/// behalf through `execute_sale`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DelegateScope {
+    Buy,
+    Sell,
+    Cancel,
+    ExecuteSale,
+    Deposit,
+    Withdraw,
+}
+
+impl From<DelegateScope> for AuthorityScope {
+    fn from(scope: DelegateScope) -> Self {
+        match scope {
+            DelegateScope::Buy => AuthorityScope::Buy,
+            DelegateScope::Sell => AuthorityScope::Sell,
+            DelegateScope::Cancel => AuthorityScope::Cancel,
+            DelegateScope::ExecuteSale => AuthorityScope::ExecuteSale,
+            DelegateScope::Deposit => AuthorityScope::Deposit,
+            DelegateScope::Withdraw => AuthorityScope::Withdraw,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DelegateAuctioneerParams {
+    pub scopes: Vec<DelegateScope>,
+    /// When `true`, clears `reward_center.auctioneer_scopes` for each
+    /// listed scope instead of granting it. The auction house program
+    /// doesn't expose a way to un-delegate a scope it already granted an
+    /// auctioneer, so revoking only stops `listings`/`offers` handlers
+    /// from trusting the scope locally -- it does not call
+    /// `delegate_auctioneer` or touch the on-chain `ah_auctioneer_pda`.
+    /// Operators who need the auction house itself to forget the scope
+    /// still have to revoke the auctioneer authority there directly.
+    pub revoke: bool,
+}
+
+/// Grants or revokes the reward center's locally-tracked auctioneer
+/// scopes without redeploying. Granting registers the reward center as
+/// an auctioneer on `auction_house` and delegates the requested
+/// `AuthorityScope`s to it via a single `delegate_auctioneer` CPI (the
+/// instruction takes the auctioneer's whole scope set and overwrites
+/// whatever was stored on the `ah_auctioneer_pda` before -- it is not
+/// additive, so every scope that should remain granted must be present
+/// in `params.scopes` on every call). Revoking skips the CPI (see
+/// `DelegateAuctioneerParams::revoke`) and only clears the local flags.
+/// Either way the resulting set is mirrored into
+/// `reward_center.auctioneer_scopes` so handlers can assert a required
+/// scope locally before CPI-ing into the auction house, instead of
+/// trusting the caller.
+pub fn handler(ctx: Context<DelegateAuctioneer>, params: DelegateAuctioneerParams) -> Result<()> {
+    require!(
+        !params.scopes.is_empty(),
+        RewardCenterError::MissingAuctioneerScope
+    );
+
+    if params.revoke {
+        for scope in &params.scopes {
+            clear_scope(&mut ctx.accounts.reward_center.auctioneer_scopes, *scope);
+        }
+
+        return Ok(());
+    }
+
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let reward_center_seeds = &[
+        REWARD_CENTER_PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        &[ctx.accounts.reward_center.bump],
+    ];
+
+    let authority_scopes: Vec<AuthorityScope> =
+        params.scopes.iter().copied().map(Into::into).collect();
+
+    delegate_auctioneer(
+        CpiContext::new_with_signer(
+            ctx.accounts.auction_house_program.to_account_info(),
+            AuctioneerDelegate {
+                auction_house: ctx.accounts.auction_house.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+                auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+                ah_auctioneer_pda: ctx.accounts.auctioneer_pda.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[reward_center_seeds],
+        ),
+        authority_scopes,
+    )?;
+
+    ctx.accounts.reward_center.auctioneer_scopes = AuctioneerScopes::default();
+    for scope in &params.scopes {
+        set_scope(&mut ctx.accounts.reward_center.auctioneer_scopes, *scope);
+    }
+
+    Ok(())
+}
+
+fn set_scope(scopes: &mut AuctioneerScopes, scope: DelegateScope) {
+    match scope {
+        DelegateScope::Buy => scopes.buy = true,
+        DelegateScope::Sell => scopes.sell = true,
+        DelegateScope::Cancel => scopes.cancel = true,
+        DelegateScope::ExecuteSale => scopes.execute_sale = true,
+        DelegateScope::Deposit => scopes.deposit = true,
+        DelegateScope::Withdraw => scopes.withdraw = true,
+    }
+}
+
+fn clear_scope(scopes: &mut AuctioneerScopes, scope: DelegateScope) {
+    match scope {
+        DelegateScope::Buy => scopes.buy = false,
+        DelegateScope::Sell => scopes.sell = false,
+        DelegateScope::Cancel => scopes.cancel = false,
+        DelegateScope::ExecuteSale => scopes.execute_sale = false,
+        DelegateScope::Deposit => scopes.deposit = false,
+        DelegateScope::Withdraw => scopes.withdraw = false,
+    }
+}
+
+#[derive(Accounts)]
+pub struct DelegateAuctioneer<'info> {
+    pub wallet: Signer<'info>,
+
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    #[account(
+        mut,
+        has_one = auction_house @ RewardCenterError::PublicKeyMismatch,
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// CHECK: the auction house authority; checked by the auction house
+    /// program itself when the CPI executes
+    #[account(constraint = authority.key() == auction_house.authority @ RewardCenterError::PublicKeyMismatch)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: initialized by the auction house program on the first
+    /// `delegate_auctioneer` CPI, seeded by `find_auctioneer_pda`
+    #[account(
+        mut,
+        seeds = [b"auctioneer", auction_house.key().as_ref(), reward_center.key().as_ref()],
+        bump,
+        seeds::program = auction_house_program.key(),
+    )]
+    pub auctioneer_pda: UncheckedAccount<'info>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub token_program: Program<'info, anchor_spl::token::Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn auctioneer_pda(auction_house: &Pubkey, auctioneer_authority: &Pubkey) -> Pubkey {
+    find_auctioneer_pda(auction_house, auctioneer_authority).0
+}
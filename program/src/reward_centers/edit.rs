@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use mpl_auction_house::AuctionHouse;
+
+use crate::{errors::RewardCenterError, state::RewardCenter};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EditRewardCenterParams {
+    pub reward_rules: crate::state::RewardRules,
+}
+
+pub fn handler(
+    ctx: Context<EditRewardCenter>,
+    EditRewardCenterParams { reward_rules }: EditRewardCenterParams,
+) -> Result<()> {
+    require!(
+        reward_rules.seller_reward_payout_basis_points <= 10_000,
+        RewardCenterError::InvalidBasisPoints
+    );
+    require!(
+        reward_rules.payout_numeral > 0,
+        RewardCenterError::InvalidPayoutNumeral
+    );
+
+    ctx.accounts.reward_center.reward_rules = reward_rules;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EditRewardCenter<'info> {
+    pub wallet: Signer<'info>,
+
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    #[account(
+        mut,
+        has_one = auction_house,
+        constraint = auction_house.authority == wallet.key() @ RewardCenterError::PublicKeyMismatch,
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+}
@@ -0,0 +1,4 @@
+pub mod create;
+pub mod delegate;
+pub mod edit;
+pub mod withdraw;
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use mpl_auction_house::AuctionHouse;
+
+use crate::{constants::REWARD_CENTER_PREFIX, errors::RewardCenterError, state::RewardCenter};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct WithdrawRewardCenterFundsParams {
+    pub amount: u64,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawRewardCenterFunds>,
+    WithdrawRewardCenterFundsParams { amount }: WithdrawRewardCenterFundsParams,
+) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let auction_house = ctx.accounts.auction_house.key();
+
+    let auction_house_seed = auction_house.as_ref();
+    let reward_center_seeds = &[
+        REWARD_CENTER_PREFIX.as_bytes(),
+        auction_house_seed,
+        &[reward_center.bump],
+    ];
+
+    require!(
+        ctx.accounts.reward_center_reward_token_account.amount >= amount,
+        RewardCenterError::InsufficientFunds
+    );
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx
+                    .accounts
+                    .reward_center_reward_token_account
+                    .to_account_info(),
+                to: ctx.accounts.destination_token_account.to_account_info(),
+                authority: ctx.accounts.reward_center.to_account_info(),
+            },
+            &[reward_center_seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawRewardCenterFunds<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    #[account(
+        mut,
+        has_one = auction_house,
+        has_one = token_mint @ RewardCenterError::PublicKeyMismatch,
+        constraint = auction_house.authority == wallet.key() @ RewardCenterError::PublicKeyMismatch,
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    #[account(mut)]
+    pub reward_center_reward_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::HUNDRED_PERCENT_BASIS_POINTS, errors::RewardCenterError};
+
+/// Operand applied to the sale price when computing the seller's reward payout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayoutOperation {
+    Divide,
+    Multiple,
+}
+
+/// Rules the reward center uses to turn a sale price into a reward token payout.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct RewardRules {
+    pub mathematical_operand: PayoutOperation,
+    pub payout_numeral: u16,
+    pub seller_reward_payout_basis_points: u16,
+}
+
+impl RewardRules {
+    /// Turns a sale `price` into the seller's reward token payout by
+    /// applying `mathematical_operand`/`payout_numeral` and then taking
+    /// `seller_reward_payout_basis_points` of the result.
+    pub fn payout(&self, price: u64) -> Result<u64> {
+        let scaled = match self.mathematical_operand {
+            PayoutOperation::Divide => price
+                .checked_div(u64::from(self.payout_numeral))
+                .ok_or(RewardCenterError::NumericalOverflow)?,
+            PayoutOperation::Multiple => price
+                .checked_mul(u64::from(self.payout_numeral))
+                .ok_or(RewardCenterError::NumericalOverflow)?,
+        };
+
+        scaled
+            .checked_mul(u64::from(self.seller_reward_payout_basis_points))
+            .and_then(|basis_product| {
+                basis_product.checked_div(u64::from(HUNDRED_PERCENT_BASIS_POINTS))
+            })
+            .ok_or_else(|| RewardCenterError::NumericalOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(operand: PayoutOperation, numeral: u16, basis_points: u16) -> RewardRules {
+        RewardRules {
+            mathematical_operand: operand,
+            payout_numeral: numeral,
+            seller_reward_payout_basis_points: basis_points,
+        }
+    }
+
+    #[test]
+    fn divide_applies_basis_points_after_dividing() {
+        let rules = rules(PayoutOperation::Divide, 2, 5_000);
+
+        // price / 2 * 50% = price / 4
+        assert_eq!(rules.payout(1_000).unwrap(), 250);
+    }
+
+    #[test]
+    fn multiple_applies_basis_points_after_multiplying() {
+        let rules = rules(PayoutOperation::Multiple, 3, 10_000);
+
+        // price * 3 * 100% = price * 3
+        assert_eq!(rules.payout(1_000).unwrap(), 3_000);
+    }
+
+    #[test]
+    fn divide_by_zero_payout_numeral_errors() {
+        let rules = rules(PayoutOperation::Divide, 0, 10_000);
+
+        assert!(rules.payout(1_000).is_err());
+    }
+
+    #[test]
+    fn multiply_overflow_errors() {
+        let rules = rules(PayoutOperation::Multiple, u16::MAX, 10_000);
+
+        assert!(rules.payout(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn basis_points_scaling_overflow_errors() {
+        // scaled value is u64::MAX, so multiplying by basis points overflows
+        // before the division by HUNDRED_PERCENT_BASIS_POINTS ever happens.
+        let rules = rules(PayoutOperation::Divide, 1, 10_000);
+
+        assert!(rules.payout(u64::MAX).is_err());
+    }
+}
+
+/// Auction house `AuthorityScope`s that have been delegated to the reward
+/// center's auctioneer PDA. Kept as plain booleans (rather than a `Vec`)
+/// so the account size is fixed and handlers can cheaply assert a single
+/// required scope before CPI-ing into the auction house.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct AuctioneerScopes {
+    pub buy: bool,
+    pub sell: bool,
+    pub cancel: bool,
+    pub execute_sale: bool,
+    pub deposit: bool,
+    pub withdraw: bool,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct RewardCenter {
+    pub token_mint: Pubkey,
+    pub auction_house: Pubkey,
+    pub bump: u8,
+    pub reward_rules: RewardRules,
+    pub auctioneer_scopes: AuctioneerScopes,
+}
+
+impl RewardCenter {
+    pub const SIZE: usize = 8 + 32 + 32 + 1 + (1 + 2 + 2) + 6;
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Listing {
+    pub reward_center: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub bump: u8,
+    pub created_at: i64,
+    pub canceled_at: Option<i64>,
+    pub purchase_ticket: Option<Pubkey>,
+}
+
+impl Listing {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1 + 8 + (1 + 8) + (1 + 32);
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Offer {
+    pub reward_center: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub bump: u8,
+    pub created_at: i64,
+    pub canceled_at: Option<i64>,
+    pub purchase_ticket: Option<Pubkey>,
+}
+
+impl Offer {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1 + 8 + (1 + 8) + (1 + 32);
+}
+
+/// Indexer-facing record of a listing. Unlike `Listing`, this account is
+/// never closed, so off-chain services can reconstruct the full history
+/// of a listing (created, purchased, or canceled) without replaying every
+/// transaction against the reward center.
+#[account]
+#[derive(Debug)]
+pub struct ListingReceipt {
+    pub listing: Pubkey,
+    pub reward_center: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub bump: u8,
+    pub created_at: i64,
+    pub canceled_at: Option<i64>,
+    pub purchased_at: Option<i64>,
+}
+
+impl ListingReceipt {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 1 + 8 + (1 + 8) + (1 + 8);
+}
+
+/// Indexer-facing record of an offer, mirroring `ListingReceipt`.
+#[account]
+#[derive(Debug)]
+pub struct BidReceipt {
+    pub offer: Pubkey,
+    pub reward_center: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub bump: u8,
+    pub created_at: i64,
+    pub canceled_at: Option<i64>,
+    pub purchased_at: Option<i64>,
+}
+
+impl BidReceipt {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 1 + 8 + (1 + 8) + (1 + 8);
+}
+
+/// Indexer-facing record of a completed sale, created alongside the
+/// `execute_sale` CPI in `listings::buy`/`offers::accept`. Records the
+/// reward-token payout the seller was credited so indexers can compute
+/// reward-weighted sales without re-deriving `RewardRules::payout`.
+#[account]
+#[derive(Debug)]
+pub struct PurchaseReceipt {
+    pub reward_center: Pubkey,
+    pub metadata: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub seller_reward_payout: u64,
+    pub bump: u8,
+    pub created_at: i64,
+}
+
+impl PurchaseReceipt {
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 1 + 8;
+}